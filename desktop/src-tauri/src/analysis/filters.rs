@@ -0,0 +1,152 @@
+//! Lightweight glob matching for `include`/`exclude` patterns and the
+//! `.drillignore` file, plus the default set of directories we never want
+//! to walk into.
+
+use std::fs;
+use std::path::Path;
+
+/// Directory names skipped even if not listed in `.drillignore`.
+pub const DEFAULT_IGNORED_DIRS: &[&str] = &["node_modules", "target", ".git"];
+
+/// Resolved include/exclude rules for a scan.
+pub struct FileFilter {
+    include: Vec<String>,
+    exclude: Vec<String>,
+}
+
+impl FileFilter {
+    pub fn new(include: Vec<String>, mut exclude: Vec<String>, root: &Path) -> Self {
+        exclude.extend(read_drillignore(root));
+        Self { include, exclude }
+    }
+
+    /// A path is analyzed if it matches at least one include pattern (or
+    /// no include patterns were given, meaning "everything") and matches
+    /// none of the exclude patterns.
+    pub fn matches(&self, relative_path: &str) -> bool {
+        if self.is_excluded(relative_path) {
+            return false;
+        }
+        self.include.is_empty() || self.include.iter().any(|p| glob_match(p, relative_path))
+    }
+
+    /// Whether a directory should be descended into. Unlike `matches`,
+    /// this ignores `include` patterns: an include pattern like
+    /// `src/**/*.ts` describes files, not the directories that contain
+    /// them, so testing it against a bare directory name (`"src"`) would
+    /// never match and the walk would never reach the files it's meant to
+    /// select. Only `exclude` patterns (and `.drillignore`) can prune a
+    /// directory from the walk.
+    pub fn should_descend(&self, relative_path: &str) -> bool {
+        !self.is_excluded(relative_path)
+    }
+
+    fn is_excluded(&self, relative_path: &str) -> bool {
+        self.exclude.iter().any(|p| glob_match(p, relative_path))
+    }
+}
+
+fn read_drillignore(root: &Path) -> Vec<String> {
+    fs::read_to_string(root.join(".drillignore"))
+        .map(|contents| {
+            contents
+                .lines()
+                .map(str::trim)
+                .filter(|l| !l.is_empty() && !l.starts_with('#'))
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Minimal glob matcher supporting `*` (any run of characters except `/`),
+/// `**` (any run of characters including `/`; `**/` also matches no
+/// directory at all) and `?` (a single character). Enough for `.drillignore`/include-exclude patterns without
+/// pulling in a full glob crate.
+pub fn glob_match(pattern: &str, path: &str) -> bool {
+    glob_match_inner(pattern.as_bytes(), path.as_bytes())
+}
+
+fn glob_match_inner(pattern: &[u8], path: &[u8]) -> bool {
+    match pattern.first() {
+        None => path.is_empty(),
+        Some(b'*') if pattern.get(1) == Some(&b'*') => {
+            let rest = &pattern[2..];
+            if let Some(after_slash) = rest.strip_prefix(b"/") {
+                if glob_match_inner(after_slash, path) {
+                    return true;
+                }
+            }
+            (0..=path.len()).any(|i| glob_match_inner(rest, &path[i..]))
+        }
+        Some(b'*') => {
+            let rest = &pattern[1..];
+            (0..=path.len())
+                .take_while(|&i| i == 0 || path[i - 1] != b'/')
+                .any(|i| glob_match_inner(rest, &path[i..]))
+        }
+        Some(b'?') => {
+            !path.is_empty() && glob_match_inner(&pattern[1..], &path[1..])
+        }
+        Some(&c) => path.first() == Some(&c) && glob_match_inner(&pattern[1..], &path[1..]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn star_does_not_cross_path_separators() {
+        assert!(glob_match("*.ts", "app.ts"));
+        assert!(!glob_match("*.ts", "src/app.ts"));
+    }
+
+    #[test]
+    fn double_star_matches_any_depth() {
+        assert!(glob_match("**/*.ts", "app.ts"));
+        assert!(glob_match("**/*.ts", "src/app.ts"));
+        assert!(glob_match("**/*.ts", "src/nested/app.ts"));
+        assert!(!glob_match("**/*.ts", "src/app.js"));
+    }
+
+    #[test]
+    fn question_mark_matches_single_char() {
+        assert!(glob_match("a?c", "abc"));
+        assert!(!glob_match("a?c", "ac"));
+        assert!(!glob_match("a?c", "abbc"));
+    }
+
+    #[test]
+    fn file_filter_applies_exclude_before_include() {
+        let filter = FileFilter {
+            include: vec!["**/*.ts".to_string()],
+            exclude: vec!["**/vendor/**".to_string()],
+        };
+        assert!(filter.matches("src/app.ts"));
+        assert!(!filter.matches("vendor/lib.ts"));
+        assert!(!filter.matches("src/app.js"));
+    }
+
+    #[test]
+    fn should_descend_ignores_include_patterns() {
+        // A file-shaped include pattern must not block descent into the
+        // directories that contain the files it's meant to select.
+        let filter = FileFilter {
+            include: vec!["src/**/*.ts".to_string()],
+            exclude: vec![],
+        };
+        assert!(filter.should_descend("src"));
+        assert!(filter.should_descend("src/nested"));
+    }
+
+    #[test]
+    fn should_descend_still_honors_exclude_patterns() {
+        let filter = FileFilter {
+            include: vec![],
+            exclude: vec!["**/node_modules/**".to_string(), "vendor".to_string()],
+        };
+        assert!(!filter.should_descend("vendor"));
+        assert!(filter.should_descend("src"));
+    }
+}
@@ -0,0 +1,218 @@
+//! The analysis engine: walks a directory, dispatches each file to the
+//! analyzers registered for its language, and aggregates the results into
+//! an [`AnalysisResult`].
+
+pub mod filters;
+pub mod rules;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use filters::{FileFilter, DEFAULT_IGNORED_DIRS};
+use serde::{Deserialize, Serialize};
+
+/// Emitted periodically during a scan so the UI can render a live progress
+/// bar instead of blocking on a single multi-second await.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnalysisProgress {
+    pub files_scanned: usize,
+    pub total_files: usize,
+    pub current_file: String,
+}
+
+use crate::{AnalysisResult, AnalysisSummary, Issue};
+
+/// A file pulled off disk and handed to analyzers.
+///
+/// `language` is derived from the file extension so analyzers can cheaply
+/// decide whether they apply without re-parsing the path themselves.
+pub struct SourceFile {
+    pub path: PathBuf,
+    pub language: Language,
+    pub contents: String,
+}
+
+/// Languages the engine knows how to classify. `Other` covers every
+/// extension without a dedicated analyzer yet; rules simply ignore it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Language {
+    JavaScript,
+    TypeScript,
+    Css,
+    Other,
+}
+
+impl Language {
+    pub(crate) fn from_extension(ext: &str) -> Self {
+        match ext {
+            "js" | "jsx" | "mjs" | "cjs" => Language::JavaScript,
+            "ts" | "tsx" => Language::TypeScript,
+            "css" | "scss" | "less" => Language::Css,
+            _ => Language::Other,
+        }
+    }
+}
+
+/// Implemented by every lint rule. A single analyzer may report any number
+/// of issues (including zero) for a given file.
+pub trait Analyzer: Send + Sync {
+    /// Inspect `file` and return the issues it finds.
+    fn analyze(&self, file: &SourceFile) -> Vec<Issue>;
+}
+
+/// Holds the set of analyzers to run and walks a directory tree through
+/// them.
+#[derive(Default)]
+pub struct RuleRegistry {
+    analyzers: Vec<Box<dyn Analyzer>>,
+}
+
+impl RuleRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register(&mut self, analyzer: Box<dyn Analyzer>) -> &mut Self {
+        self.analyzers.push(analyzer);
+        self
+    }
+
+    /// The default registry DrillSargeant ships with: one analyzer per
+    /// `issue_type` category.
+    pub fn with_default_rules() -> Self {
+        let mut registry = Self::new();
+        registry
+            .register(Box::new(rules::security::UnsanitizedInnerHtmlRule))
+            .register(Box::new(rules::performance::InefficientCssSelectorRule))
+            .register(Box::new(rules::quality::UnusedVariableRule));
+        registry
+    }
+
+    /// Run every registered analyzer over a single already-loaded file.
+    /// Used by the watcher to re-analyze just the file that changed
+    /// instead of re-scanning the whole tree.
+    pub fn analyze_file(&self, file: &SourceFile) -> Vec<Issue> {
+        self.analyzers.iter().flat_map(|a| a.analyze(file)).collect()
+    }
+
+    /// Recursively walk `root`, analyze every file we recognize, and
+    /// aggregate the results. A convenience wrapper around
+    /// [`RuleRegistry::analyze_roots`] for the common single-root case.
+    pub fn analyze_directory(&self, root: &Path) -> Result<AnalysisResult, String> {
+        self.analyze_roots(&[root.to_path_buf()], &[], &[])
+    }
+
+    /// Walk every root in `roots`, filtering each file through `include`/
+    /// `exclude` glob patterns (and that root's `.drillignore`, if any),
+    /// and aggregate the results across all of them.
+    pub fn analyze_roots(
+        &self,
+        roots: &[PathBuf],
+        include: &[String],
+        exclude: &[String],
+    ) -> Result<AnalysisResult, String> {
+        self.analyze_roots_with_progress(roots, include, exclude, |_| {})
+    }
+
+    /// Same as [`RuleRegistry::analyze_roots`], but invokes `on_progress`
+    /// after each file so long scans can drive a live progress bar rather
+    /// than blocking on one large await.
+    pub fn analyze_roots_with_progress(
+        &self,
+        roots: &[PathBuf],
+        include: &[String],
+        exclude: &[String],
+        mut on_progress: impl FnMut(AnalysisProgress),
+    ) -> Result<AnalysisResult, String> {
+        let matched_files = collect_matched_files(roots, include, exclude)?;
+        let total_files = matched_files.len();
+
+        let mut analyzed_files = 0usize;
+        let mut issues = Vec::new();
+        for (scanned, path) in matched_files.into_iter().enumerate() {
+            if let Some(source_file) = load_source_file(&path) {
+                analyzed_files += 1;
+                for analyzer in &self.analyzers {
+                    issues.extend(analyzer.analyze(&source_file));
+                }
+            }
+            on_progress(AnalysisProgress {
+                files_scanned: scanned + 1,
+                total_files,
+                current_file: path.display().to_string(),
+            });
+        }
+
+        let summary = AnalysisSummary {
+            total_issues: issues.len(),
+            high_severity: issues.iter().filter(|i| i.severity == "high").count(),
+            medium_severity: issues.iter().filter(|i| i.severity == "medium").count(),
+            low_severity: issues.iter().filter(|i| i.severity == "low").count(),
+        };
+
+        Ok(AnalysisResult {
+            total_files,
+            analyzed_files,
+            issues,
+            summary,
+        })
+    }
+}
+
+/// Walks `roots` applying the include/exclude filters and returns every
+/// file that should be analyzed, without reading any of them yet — lets
+/// us report an accurate `total_files` in progress events up front.
+fn collect_matched_files(
+    roots: &[PathBuf],
+    include: &[String],
+    exclude: &[String],
+) -> Result<Vec<PathBuf>, String> {
+    let mut matched = Vec::new();
+    for root in roots {
+        let filter = FileFilter::new(include.to_vec(), exclude.to_vec(), root);
+        let mut stack = vec![root.clone()];
+        while let Some(dir) = stack.pop() {
+            let entries = fs::read_dir(&dir)
+                .map_err(|e| format!("failed to read directory {}: {e}", dir.display()))?;
+            for entry in entries {
+                let entry = entry.map_err(|e| e.to_string())?;
+                let path = entry.path();
+                if is_ignored(&path) {
+                    continue;
+                }
+
+                let relative = path.strip_prefix(root).unwrap_or(&path).to_string_lossy().replace('\\', "/");
+                if path.is_dir() {
+                    if filter.should_descend(&relative) {
+                        stack.push(path);
+                    }
+                    continue;
+                }
+                if filter.matches(&relative) {
+                    matched.push(path);
+                }
+            }
+        }
+    }
+    Ok(matched)
+}
+
+fn is_ignored(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|n| n.to_str())
+        .is_some_and(|name| DEFAULT_IGNORED_DIRS.contains(&name))
+}
+
+pub(crate) fn load_source_file(path: &Path) -> Option<SourceFile> {
+    let ext = path.extension()?.to_str()?;
+    let language = Language::from_extension(ext);
+    if language == Language::Other {
+        return None;
+    }
+    let contents = fs::read_to_string(path).ok()?;
+    Some(SourceFile {
+        path: path.to_path_buf(),
+        language,
+        contents,
+    })
+}
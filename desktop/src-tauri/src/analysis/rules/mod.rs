@@ -0,0 +1,21 @@
+//! Concrete [`Analyzer`](crate::analysis::Analyzer) implementations, one
+//! module per `issue_type` category.
+
+pub mod performance;
+pub mod quality;
+pub mod security;
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use crate::analysis::SourceFile;
+
+/// Builds a stable, human-readable issue id from the rule name, file path
+/// and line number so re-running analysis on an unchanged file always
+/// yields the same id.
+fn issue_id(rule: &str, file: &SourceFile, line_number: u32) -> String {
+    let mut hasher = DefaultHasher::new();
+    file.path.hash(&mut hasher);
+    line_number.hash(&mut hasher);
+    format!("{rule}_{:x}", hasher.finish())
+}
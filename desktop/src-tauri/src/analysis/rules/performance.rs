@@ -0,0 +1,87 @@
+use crate::analysis::rules::issue_id;
+use crate::analysis::{Analyzer, Language, SourceFile};
+use crate::Issue;
+
+/// Flags CSS selectors with more than three combinators, which are slow to
+/// match and usually a sign the markup could use a dedicated class instead.
+pub struct InefficientCssSelectorRule;
+
+const MAX_COMBINATORS: usize = 3;
+
+impl Analyzer for InefficientCssSelectorRule {
+    fn analyze(&self, file: &SourceFile) -> Vec<Issue> {
+        if file.language != Language::Css {
+            return Vec::new();
+        }
+
+        let mut issues = Vec::new();
+        for (idx, line) in file.contents.lines().enumerate() {
+            let Some(selector) = line.split('{').next() else {
+                continue;
+            };
+            let selector = selector.trim();
+            if selector.is_empty() {
+                continue;
+            }
+            let combinators = selector
+                .replace(['>', '~', '+'], " ")
+                .split_whitespace()
+                .count()
+                .saturating_sub(1);
+            if combinators > MAX_COMBINATORS {
+                let line_number = (idx + 1) as u32;
+                issues.push(Issue {
+                    id: issue_id("performance", file, line_number),
+                    title: "Inefficient CSS Selector".to_string(),
+                    description: "Complex CSS selector may impact performance".to_string(),
+                    severity: "medium".to_string(),
+                    issue_type: "performance".to_string(),
+                    file_path: file.path.display().to_string(),
+                    line_number,
+                    code_snippet: selector.to_string(),
+                    recommendation: "Consider using CSS classes for better performance"
+                        .to_string(),
+                });
+            }
+        }
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn source_file(contents: &str) -> SourceFile {
+        SourceFile {
+            path: PathBuf::from("src/styles/main.css"),
+            language: Language::Css,
+            contents: contents.to_string(),
+        }
+    }
+
+    #[test]
+    fn flags_selector_over_the_combinator_limit() {
+        let file = source_file("div > ul > li:nth-child(odd) > a[href*='example'] > span {\n  color: red;\n}");
+        let issues = InefficientCssSelectorRule.analyze(&file);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].severity, "medium");
+    }
+
+    #[test]
+    fn allows_simple_selector() {
+        let file = source_file(".button {\n  color: red;\n}");
+        assert!(InefficientCssSelectorRule.analyze(&file).is_empty());
+    }
+
+    #[test]
+    fn ignores_non_css_languages() {
+        let file = SourceFile {
+            path: PathBuf::from("src/app.ts"),
+            language: Language::TypeScript,
+            contents: "div > ul > li:nth-child(odd) > a[href*='example'] {}".to_string(),
+        };
+        assert!(InefficientCssSelectorRule.analyze(&file).is_empty());
+    }
+}
@@ -0,0 +1,91 @@
+use crate::analysis::rules::issue_id;
+use crate::analysis::{Analyzer, Language, SourceFile};
+use crate::Issue;
+
+/// Flags `const`/`let`/`var` declarations whose name never appears again
+/// in the file. A cheap heuristic rather than real scope analysis, but
+/// enough to catch the common "declared and forgotten" case.
+pub struct UnusedVariableRule;
+
+impl Analyzer for UnusedVariableRule {
+    fn analyze(&self, file: &SourceFile) -> Vec<Issue> {
+        if !matches!(file.language, Language::JavaScript | Language::TypeScript) {
+            return Vec::new();
+        }
+
+        let mut issues = Vec::new();
+        for (idx, line) in file.contents.lines().enumerate() {
+            let trimmed = line.trim();
+            let Some(name) = declared_variable_name(trimmed) else {
+                continue;
+            };
+            let occurrences = file.contents.matches(name).count();
+            if occurrences <= 1 {
+                let line_number = (idx + 1) as u32;
+                issues.push(Issue {
+                    id: issue_id("quality", file, line_number),
+                    title: "Unused Variable".to_string(),
+                    description: "Variable declared but never used".to_string(),
+                    severity: "low".to_string(),
+                    issue_type: "quality".to_string(),
+                    file_path: file.path.display().to_string(),
+                    line_number,
+                    code_snippet: trimmed.to_string(),
+                    recommendation: "Remove unused variables to improve code clarity".to_string(),
+                });
+            }
+        }
+        issues
+    }
+}
+
+/// Returns the declared identifier for a simple `const foo = ...` /
+/// `let foo = ...` / `var foo = ...` statement, or `None` if the line
+/// doesn't look like one (destructuring, multi-declaration, etc. are
+/// intentionally left to real tooling).
+fn declared_variable_name(line: &str) -> Option<&str> {
+    let rest = line
+        .strip_prefix("const ")
+        .or_else(|| line.strip_prefix("let "))
+        .or_else(|| line.strip_prefix("var "))?;
+    let name = rest.split(['=', ' ']).next()?.trim();
+    if name.is_empty() || name.starts_with(['{', '[']) {
+        return None;
+    }
+    Some(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn source_file(contents: &str) -> SourceFile {
+        SourceFile {
+            path: PathBuf::from("src/utils/helpers.js"),
+            language: Language::JavaScript,
+            contents: contents.to_string(),
+        }
+    }
+
+    #[test]
+    fn flags_variable_declared_but_never_referenced_again() {
+        let file = source_file("const unusedVar = 'not used';\nconsole.log('hi');");
+        let issues = UnusedVariableRule.analyze(&file);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].severity, "low");
+    }
+
+    #[test]
+    fn allows_variable_used_later_in_the_file() {
+        let file = source_file("const greeting = 'hi';\nconsole.log(greeting);");
+        assert!(UnusedVariableRule.analyze(&file).is_empty());
+    }
+
+    #[test]
+    fn declared_variable_name_rejects_destructuring() {
+        assert_eq!(declared_variable_name("const foo = 1;"), Some("foo"));
+        assert_eq!(declared_variable_name("const { a, b } = obj;"), None);
+        assert_eq!(declared_variable_name("return foo;"), None);
+    }
+}
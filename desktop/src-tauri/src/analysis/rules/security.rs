@@ -0,0 +1,109 @@
+use crate::analysis::rules::issue_id;
+use crate::analysis::{Analyzer, Language, SourceFile};
+use crate::Issue;
+
+/// Flags direct `.innerHTML = ...` assignments, which are a classic XSS
+/// vector when the right-hand side isn't sanitized first.
+pub struct UnsanitizedInnerHtmlRule;
+
+impl Analyzer for UnsanitizedInnerHtmlRule {
+    fn analyze(&self, file: &SourceFile) -> Vec<Issue> {
+        if !matches!(file.language, Language::JavaScript | Language::TypeScript) {
+            return Vec::new();
+        }
+
+        let mut issues = Vec::new();
+        for (idx, line) in file.contents.lines().enumerate() {
+            let trimmed = line.trim();
+            if trimmed
+                .find(".innerHTML")
+                .is_some_and(|i| has_assignment(&trimmed[i..]))
+            {
+                let line_number = (idx + 1) as u32;
+                issues.push(Issue {
+                    id: issue_id("security", file, line_number),
+                    title: "Potential XSS Vulnerability".to_string(),
+                    description: "Direct innerHTML assignment without sanitization".to_string(),
+                    severity: "high".to_string(),
+                    issue_type: "security".to_string(),
+                    file_path: file.path.display().to_string(),
+                    line_number,
+                    code_snippet: trimmed.to_string(),
+                    recommendation: "Use textContent or sanitize input before assignment"
+                        .to_string(),
+                });
+            }
+        }
+        issues
+    }
+}
+
+/// Whether `line` contains a plain `=` assignment, as opposed to a
+/// comparison (`==`, `!=`, `<=`, `>=`) or an arrow function (`=>`).
+fn has_assignment(line: &str) -> bool {
+    let bytes = line.as_bytes();
+    for (i, &b) in bytes.iter().enumerate() {
+        if b != b'=' {
+            continue;
+        }
+        let prev = if i > 0 { Some(bytes[i - 1]) } else { None };
+        let next = bytes.get(i + 1).copied();
+        if matches!(next, Some(b'=') | Some(b'>')) {
+            continue;
+        }
+        if matches!(prev, Some(b'!') | Some(b'<') | Some(b'>') | Some(b'=')) {
+            continue;
+        }
+        return true;
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn source_file(language: Language, contents: &str) -> SourceFile {
+        SourceFile {
+            path: PathBuf::from("src/App.tsx"),
+            language,
+            contents: contents.to_string(),
+        }
+    }
+
+    #[test]
+    fn flags_unsanitized_innerhtml_assignment() {
+        let file = source_file(Language::TypeScript, "element.innerHTML = userInput;");
+        let issues = UnsanitizedInnerHtmlRule.analyze(&file);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].severity, "high");
+        assert_eq!(issues[0].line_number, 1);
+    }
+
+    #[test]
+    fn ignores_innerhtml_equality_comparison() {
+        let file = source_file(Language::TypeScript, "if (element.innerHTML == cached) {}");
+        assert!(UnsanitizedInnerHtmlRule.analyze(&file).is_empty());
+    }
+
+    #[test]
+    fn ignores_innerhtml_inequality_comparison() {
+        let file = source_file(Language::TypeScript, "if (element.innerHTML != cached) {}");
+        assert!(UnsanitizedInnerHtmlRule.analyze(&file).is_empty());
+    }
+
+    #[test]
+    fn ignores_innerhtml_relational_comparisons() {
+        let le = source_file(Language::TypeScript, "if (element.innerHTML <= cached) {}");
+        let ge = source_file(Language::TypeScript, "if (element.innerHTML >= cached) {}");
+        assert!(UnsanitizedInnerHtmlRule.analyze(&le).is_empty());
+        assert!(UnsanitizedInnerHtmlRule.analyze(&ge).is_empty());
+    }
+
+    #[test]
+    fn ignores_non_js_languages() {
+        let file = source_file(Language::Css, "element.innerHTML = userInput;");
+        assert!(UnsanitizedInnerHtmlRule.analyze(&file).is_empty());
+    }
+}
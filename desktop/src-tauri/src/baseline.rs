@@ -0,0 +1,164 @@
+//! Baseline snapshots: persist a prior [`AnalysisResult`] and diff a new
+//! run against it so CI can be made to fail only on newly introduced
+//! issues ("ratcheting") instead of the whole backlog at once.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{AnalysisResult, Issue};
+
+/// `new` issues are not in the baseline, `fixed` issues were in the
+/// baseline but are no longer present, `unchanged` issues appear in both.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BaselineDiff {
+    pub new: Vec<Issue>,
+    pub fixed: Vec<Issue>,
+    pub unchanged: Vec<Issue>,
+}
+
+/// Persist `result` as the baseline at `path`.
+pub fn save_baseline(result: &AnalysisResult, path: &Path) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(result).map_err(|e| e.to_string())?;
+    std::fs::write(path, json).map_err(|e| e.to_string())
+}
+
+/// Load the baseline at `path` and diff `current` against it.
+pub fn diff_against_baseline(current: AnalysisResult, baseline_path: &Path) -> Result<BaselineDiff, String> {
+    let raw = std::fs::read_to_string(baseline_path).map_err(|e| e.to_string())?;
+    let baseline: AnalysisResult = serde_json::from_str(&raw).map_err(|e| e.to_string())?;
+
+    let baseline_fingerprints: HashSet<u64> =
+        baseline.issues.iter().map(fingerprint).collect();
+    let current_fingerprints: HashSet<u64> =
+        current.issues.iter().map(fingerprint).collect();
+
+    let mut new = Vec::new();
+    let mut unchanged = Vec::new();
+    for issue in current.issues {
+        if baseline_fingerprints.contains(&fingerprint(&issue)) {
+            unchanged.push(issue);
+        } else {
+            new.push(issue);
+        }
+    }
+
+    let fixed = baseline
+        .issues
+        .into_iter()
+        .filter(|issue| !current_fingerprints.contains(&fingerprint(issue)))
+        .collect();
+
+    Ok(BaselineDiff { new, fixed, unchanged })
+}
+
+/// A stable fingerprint for an issue: `issue_type` + `title` + normalized
+/// `file_path` + `code_snippet`. `line_number` is deliberately excluded so
+/// unrelated edits that merely shift line numbers don't resurrect issues
+/// that were already accepted or fixed.
+fn fingerprint(issue: &Issue) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    issue.issue_type.hash(&mut hasher);
+    issue.title.hash(&mut hasher);
+    normalize_path(&issue.file_path).hash(&mut hasher);
+    issue.code_snippet.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn normalize_path(path: &str) -> String {
+    path.replace('\\', "/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AnalysisSummary;
+
+    fn issue(title: &str, file_path: &str, line_number: u32, code_snippet: &str) -> Issue {
+        Issue {
+            id: format!("{title}-{line_number}"),
+            title: title.to_string(),
+            description: "description".to_string(),
+            severity: "high".to_string(),
+            issue_type: "security".to_string(),
+            file_path: file_path.to_string(),
+            line_number,
+            code_snippet: code_snippet.to_string(),
+            recommendation: "recommendation".to_string(),
+        }
+    }
+
+    fn result(issues: Vec<Issue>) -> AnalysisResult {
+        AnalysisResult {
+            total_files: issues.len(),
+            analyzed_files: issues.len(),
+            summary: AnalysisSummary {
+                total_issues: issues.len(),
+                high_severity: issues.len(),
+                medium_severity: 0,
+                low_severity: 0,
+            },
+            issues,
+        }
+    }
+
+    fn temp_baseline_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!(
+            "drillsargeant-baseline-test-{name}-{}.json",
+            std::process::id()
+        ))
+    }
+
+    #[test]
+    fn fingerprint_ignores_line_number() {
+        let at_line_10 = issue("XSS", "src/App.tsx", 10, "el.innerHTML = x;");
+        let at_line_20 = issue("XSS", "src/App.tsx", 20, "el.innerHTML = x;");
+        assert_eq!(fingerprint(&at_line_10), fingerprint(&at_line_20));
+    }
+
+    #[test]
+    fn fingerprint_normalizes_path_separators() {
+        let windows_path = issue("XSS", "src\\App.tsx", 10, "el.innerHTML = x;");
+        let unix_path = issue("XSS", "src/App.tsx", 10, "el.innerHTML = x;");
+        assert_eq!(fingerprint(&windows_path), fingerprint(&unix_path));
+    }
+
+    #[test]
+    fn diff_reports_same_issue_at_new_line_as_unchanged_not_new() {
+        let path = temp_baseline_path("unchanged");
+        let baseline = result(vec![issue("XSS", "src/App.tsx", 10, "el.innerHTML = x;")]);
+        save_baseline(&baseline, &path).unwrap();
+
+        let current = result(vec![issue("XSS", "src/App.tsx", 42, "el.innerHTML = x;")]);
+        let diff = diff_against_baseline(current, &path).unwrap();
+
+        assert!(diff.new.is_empty());
+        assert_eq!(diff.unchanged.len(), 1);
+        assert!(diff.fixed.is_empty());
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn diff_reports_issue_missing_from_current_as_fixed() {
+        let path = temp_baseline_path("fixed");
+        let baseline = result(vec![
+            issue("XSS", "src/App.tsx", 10, "el.innerHTML = x;"),
+            issue("Unused Variable", "src/utils.js", 5, "const unused = 1;"),
+        ]);
+        save_baseline(&baseline, &path).unwrap();
+
+        let current = result(vec![issue("XSS", "src/App.tsx", 10, "el.innerHTML = x;")]);
+        let diff = diff_against_baseline(current, &path).unwrap();
+
+        assert_eq!(diff.fixed.len(), 1);
+        assert_eq!(diff.fixed[0].title, "Unused Variable");
+        assert_eq!(diff.unchanged.len(), 1);
+        assert!(diff.new.is_empty());
+
+        std::fs::remove_file(&path).ok();
+    }
+}
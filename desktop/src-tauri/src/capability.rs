@@ -0,0 +1,124 @@
+//! Tracks which directories the user has actually authorized (by picking
+//! them through the dialog plugin) and grants the `fs` plugin scope to
+//! exactly that subtree. Commands that touch the filesystem reject any
+//! path outside the granted set instead of relying on an unscoped,
+//! unrestricted `tauri_plugin_fs::init()`.
+
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use tauri::{AppHandle, Manager};
+use tauri_plugin_fs::FsExt;
+
+/// The set of directories currently authorized for filesystem access.
+#[derive(Default)]
+pub struct ScopeRegistry {
+    allowed_roots: Mutex<Vec<PathBuf>>,
+}
+
+impl ScopeRegistry {
+    /// Grant `root` read access through the `fs` plugin scope and remember
+    /// it so future requests can be validated against it. The root is
+    /// canonicalized first so later `..`-relative comparisons in `check`
+    /// can't be fooled by a non-normalized grant.
+    pub fn grant(&self, app: &AppHandle, root: PathBuf) -> Result<(), String> {
+        let root = root
+            .canonicalize()
+            .map_err(|e| format!("{} is not a valid directory: {e}", root.display()))?;
+        app.fs_scope()
+            .allow_directory(&root, true)
+            .map_err(|e| e.to_string())?;
+        let mut roots = self.allowed_roots.lock().map_err(|e| e.to_string())?;
+        if !roots.contains(&root) {
+            roots.push(root);
+        }
+        Ok(())
+    }
+
+    /// Reject `path` unless it falls under a previously granted root.
+    /// `path` is canonicalized before comparison so a `..`-laden argument
+    /// (e.g. `/granted/root/../../etc`) can't masquerade as being inside
+    /// an authorized directory.
+    pub fn check(&self, path: &Path) -> Result<(), String> {
+        let canonical = path
+            .canonicalize()
+            .map_err(|e| format!("{} is not accessible: {e}", path.display()))?;
+        let roots = self.allowed_roots.lock().map_err(|e| e.to_string())?;
+        if roots.iter().any(|root| canonical.starts_with(root)) {
+            Ok(())
+        } else {
+            Err(format!(
+                "{} is outside the authorized directories; select it via the directory picker first",
+                path.display()
+            ))
+        }
+    }
+
+    /// The directories currently authorized, for display in the frontend.
+    pub fn allowed_roots(&self) -> Result<Vec<String>, String> {
+        let roots = self.allowed_roots.lock().map_err(|e| e.to_string())?;
+        Ok(roots.iter().map(|p| p.display().to_string()).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    /// Creates `<tmp>/drillsargeant-capability-test-<name>-<pid>/{root,root2}`
+    /// and returns the canonicalized root and sibling. `root2` shares only a
+    /// string prefix with `root`, and it exists on disk so `check` rejects
+    /// it by the prefix comparison rather than by failing to canonicalize.
+    fn setup_dirs(name: &str) -> (PathBuf, PathBuf) {
+        let base = std::env::temp_dir().join(format!(
+            "drillsargeant-capability-test-{name}-{}",
+            std::process::id()
+        ));
+        let root = base.join("root");
+        let sibling = base.join("root2");
+        fs::create_dir_all(&root).unwrap();
+        fs::create_dir_all(&sibling).unwrap();
+        (root.canonicalize().unwrap(), sibling.canonicalize().unwrap())
+    }
+
+    /// Registers `root` as authorized without going through `grant`, since
+    /// `grant` needs a live `AppHandle` to register the `fs` plugin scope.
+    /// This exercises the exact set `check` consults, which is the part
+    /// that actually enforces the security boundary.
+    fn registry_with_root(root: &Path) -> ScopeRegistry {
+        let registry = ScopeRegistry::default();
+        registry.allowed_roots.lock().unwrap().push(root.to_path_buf());
+        registry
+    }
+
+    #[test]
+    fn check_allows_path_under_granted_root() {
+        let (root, _sibling) = setup_dirs("allow");
+        let nested = root.join("src");
+        fs::create_dir_all(&nested).unwrap();
+        let registry = registry_with_root(&root);
+
+        assert!(registry.check(&root).is_ok());
+        assert!(registry.check(&nested).is_ok());
+
+        fs::remove_dir_all(root.parent().unwrap()).ok();
+    }
+
+    #[test]
+    fn check_rejects_sibling_directory_with_shared_prefix() {
+        let (root, sibling) = setup_dirs("sibling");
+        let registry = registry_with_root(&root);
+        assert!(registry.check(&sibling).is_err());
+        fs::remove_dir_all(root.parent().unwrap()).ok();
+    }
+
+    #[test]
+    fn check_rejects_dot_dot_traversal_that_resolves_outside_root() {
+        let (root, sibling) = setup_dirs("traversal");
+        let registry = registry_with_root(&root);
+        let traversal = root.join("..").join(sibling.file_name().unwrap());
+        assert!(registry.check(&traversal).is_err());
+        fs::remove_dir_all(root.parent().unwrap()).ok();
+    }
+}
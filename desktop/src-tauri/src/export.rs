@@ -0,0 +1,93 @@
+//! Serializing an [`AnalysisResult`] into report formats external tooling
+//! understands, starting with JUnit XML for CI pipelines.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+
+use crate::{AnalysisResult, Issue};
+
+/// Serialize `result` into the requested `format`. Only `"junit"` is
+/// supported today; unknown formats are a caller error, not a panic.
+pub fn export_results(result: &AnalysisResult, format: &str) -> Result<String, String> {
+    match format {
+        "junit" => Ok(to_junit_xml(result)),
+        other => Err(format!("unsupported export format: {other}")),
+    }
+}
+
+/// One `<testsuite>` per `issue_type`, one `<testcase>` per issue.
+/// `high`/`medium` severity issues are reported as `<failure>`, `low`
+/// severity issues pass (they're informational, not CI-breaking).
+fn to_junit_xml(result: &AnalysisResult) -> String {
+    let mut suites: BTreeMap<&str, Vec<&Issue>> = BTreeMap::new();
+    for issue in &result.issues {
+        suites.entry(issue.issue_type.as_str()).or_default().push(issue);
+    }
+
+    let mut xml = String::new();
+    let _ = writeln!(xml, r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    let _ = writeln!(
+        xml,
+        r#"<testsuites tests="{}" failures="{}" errors="0">"#,
+        result.summary.total_issues,
+        result.summary.high_severity + result.summary.medium_severity
+    );
+
+    for (issue_type, issues) in &suites {
+        let failures = issues
+            .iter()
+            .filter(|i| i.severity == "high" || i.severity == "medium")
+            .count();
+        let _ = writeln!(
+            xml,
+            r#"  <testsuite name="{}" tests="{}" failures="{}" errors="0">"#,
+            escape(issue_type),
+            issues.len(),
+            failures
+        );
+        for issue in issues {
+            write_testcase(&mut xml, issue);
+        }
+        let _ = writeln!(xml, "  </testsuite>");
+    }
+
+    let _ = writeln!(xml, "</testsuites>");
+    xml
+}
+
+fn write_testcase(xml: &mut String, issue: &Issue) {
+    let name = format!("{}:{}", issue.file_path, issue.line_number);
+    let _ = writeln!(
+        xml,
+        r#"    <testcase name="{}" classname="{}">"#,
+        escape(&issue.title),
+        escape(&name)
+    );
+
+    let body = format!(
+        "{}\n\n{}\n\nRecommendation: {}",
+        issue.description, issue.code_snippet, issue.recommendation
+    );
+    match issue.severity.as_str() {
+        "high" | "medium" => {
+            let _ = writeln!(
+                xml,
+                r#"      <failure message="{}">{}</failure>"#,
+                escape(&issue.title),
+                escape(&body)
+            );
+        }
+        _ => {
+            let _ = writeln!(xml, "      <system-out>{}</system-out>", escape(&body));
+        }
+    }
+    let _ = writeln!(xml, "    </testcase>");
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
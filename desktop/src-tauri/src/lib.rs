@@ -1,4 +1,16 @@
+mod analysis;
+mod baseline;
+mod capability;
+mod export;
+mod watcher;
+
 use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager, State};
+
+use analysis::{AnalysisProgress, RuleRegistry};
+use capability::ScopeRegistry;
+use tauri::ipc::Channel;
+use watcher::WatcherRegistry;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct AnalysisResult {
@@ -30,61 +42,46 @@ pub struct AnalysisSummary {
 }
 
 #[tauri::command]
-async fn analyze_directory(path: String) -> Result<AnalysisResult, String> {
-    println!("🔍 Analyzing directory: {}", path);
-    
-    // Simulate comprehensive analysis
-    let issues = vec![
-        Issue {
-            id: "security_1".to_string(),
-            title: "Potential XSS Vulnerability".to_string(),
-            description: "Direct innerHTML assignment without sanitization".to_string(),
-            severity: "high".to_string(),
-            issue_type: "security".to_string(),
-            file_path: "src/components/App.tsx".to_string(),
-            line_number: 42,
-            code_snippet: "element.innerHTML = userInput;".to_string(),
-            recommendation: "Use textContent or sanitize input before assignment".to_string(),
-        },
-        Issue {
-            id: "performance_1".to_string(),
-            title: "Inefficient CSS Selector".to_string(),
-            description: "Complex CSS selector may impact performance".to_string(),
-            severity: "medium".to_string(),
-            issue_type: "performance".to_string(),
-            file_path: "src/styles/main.css".to_string(),
-            line_number: 15,
-            code_snippet: "div > ul > li:nth-child(odd) > a[href*='example']".to_string(),
-            recommendation: "Consider using CSS classes for better performance".to_string(),
-        },
-        Issue {
-            id: "quality_1".to_string(),
-            title: "Unused Variable".to_string(),
-            description: "Variable declared but never used".to_string(),
-            severity: "low".to_string(),
-            issue_type: "quality".to_string(),
-            file_path: "src/utils/helpers.js".to_string(),
-            line_number: 8,
-            code_snippet: "const unusedVar = 'not used';".to_string(),
-            recommendation: "Remove unused variables to improve code clarity".to_string(),
-        },
-    ];
-
-    let summary = AnalysisSummary {
-        total_issues: issues.len(),
-        high_severity: issues.iter().filter(|i| i.severity == "high").count(),
-        medium_severity: issues.iter().filter(|i| i.severity == "medium").count(),
-        low_severity: issues.iter().filter(|i| i.severity == "low").count(),
-    };
-
-    Ok(AnalysisResult {
-        total_files: 127,
-        analyzed_files: 89,
-        issues,
-        summary,
+async fn analyze_directory(
+    scope: State<'_, ScopeRegistry>,
+    roots: Vec<String>,
+    include: Vec<String>,
+    exclude: Vec<String>,
+    progress: Channel<AnalysisProgress>,
+) -> Result<AnalysisResult, String> {
+    log::info!("analyzing {} root(s)", roots.len());
+
+    let roots: Vec<std::path::PathBuf> = roots.into_iter().map(std::path::PathBuf::from).collect();
+    for root in &roots {
+        scope.check(root)?;
+    }
+
+    let registry = RuleRegistry::with_default_rules();
+    registry.analyze_roots_with_progress(&roots, &include, &exclude, |update| {
+        if let Err(e) = progress.send(update) {
+            log::error!("failed to send analysis progress: {e}");
+        }
     })
 }
 
+/// Grant filesystem access to `path`. Call this with whatever directory
+/// the user picked via `tauri_plugin_dialog` before analyzing or watching
+/// it — `analyze_directory`/`watch_directory` reject anything outside the
+/// granted set.
+#[tauri::command]
+async fn authorize_directory(
+    app: AppHandle,
+    scope: State<'_, ScopeRegistry>,
+    path: String,
+) -> Result<(), String> {
+    scope.grant(&app, std::path::PathBuf::from(path))
+}
+
+#[tauri::command]
+async fn get_authorized_roots(scope: State<'_, ScopeRegistry>) -> Result<Vec<String>, String> {
+    scope.allowed_roots()
+}
+
 #[tauri::command]
 async fn get_system_info() -> Result<String, String> {
     let info = format!(
@@ -96,24 +93,77 @@ async fn get_system_info() -> Result<String, String> {
 }
 
 #[tauri::command]
-async fn watch_directory(path: String) -> Result<String, String> {
-    println!("👁️ Setting up file watcher for: {}", path);
-    // Placeholder for file watching implementation
-    Ok(format!("Started monitoring: {}", path))
+async fn watch_directory(
+    app: AppHandle,
+    registry: State<'_, WatcherRegistry>,
+    scope: State<'_, ScopeRegistry>,
+    path: String,
+) -> Result<String, String> {
+    log::info!("setting up file watcher for: {path}");
+    let path = std::path::PathBuf::from(&path);
+    scope.check(&path)?;
+    registry.watch(app, path.clone())?;
+    Ok(format!("Started monitoring: {}", path.display()))
+}
+
+#[tauri::command]
+async fn export_results(result: AnalysisResult, format: String) -> Result<String, String> {
+    export::export_results(&result, &format)
+}
+
+#[tauri::command]
+async fn unwatch_directory(registry: State<'_, WatcherRegistry>, path: String) -> Result<(), String> {
+    registry.unwatch(std::path::Path::new(&path))
+}
+
+#[tauri::command]
+async fn save_baseline(
+    scope: State<'_, ScopeRegistry>,
+    result: AnalysisResult,
+    path: String,
+) -> Result<(), String> {
+    let path = std::path::PathBuf::from(path);
+    scope.check(path.parent().unwrap_or(&path))?;
+    baseline::save_baseline(&result, &path)
+}
+
+#[tauri::command]
+async fn analyze_directory_against_baseline(
+    scope: State<'_, ScopeRegistry>,
+    dir: String,
+    baseline_path: String,
+) -> Result<baseline::BaselineDiff, String> {
+    let dir = std::path::PathBuf::from(dir);
+    scope.check(&dir)?;
+    let baseline_path = std::path::PathBuf::from(baseline_path);
+    scope.check(baseline_path.parent().unwrap_or(&baseline_path))?;
+
+    let registry = RuleRegistry::with_default_rules();
+    let result = registry.analyze_directory(&dir)?;
+    baseline::diff_against_baseline(result, &baseline_path)
 }
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
+        .plugin(tauri_plugin_log::Builder::new().level(log::LevelFilter::Info).build())
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_fs::init())
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_notification::init())
         .plugin(tauri_plugin_shell::init())
+        .manage(WatcherRegistry::default())
+        .manage(ScopeRegistry::default())
         .invoke_handler(tauri::generate_handler![
             analyze_directory,
             get_system_info,
-            watch_directory
+            watch_directory,
+            unwatch_directory,
+            export_results,
+            save_baseline,
+            analyze_directory_against_baseline,
+            authorize_directory,
+            get_authorized_roots
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
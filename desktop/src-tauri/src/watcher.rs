@@ -0,0 +1,183 @@
+//! Recursive file watching. Each watched root gets a `notify` watcher that
+//! re-runs the analyzer on the files it touches and pushes the delta to the
+//! frontend as an `analysis://file-changed` event, instead of requiring a
+//! full re-scan of the tree.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use notify::event::{ModifyKind, RenameMode};
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter};
+
+use crate::analysis::{load_source_file, RuleRegistry};
+use crate::Issue;
+
+const FILE_CHANGE_EVENT: &str = "analysis://file-changed";
+
+/// Kind of filesystem change that triggered re-analysis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChangeKind {
+    Created,
+    Modified,
+    Removed,
+}
+
+/// Payload emitted to the frontend whenever a watched file changes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileChange {
+    pub path: String,
+    pub kind: ChangeKind,
+    pub issues: Vec<Issue>,
+}
+
+/// Keyed by watched root so repeated `watch_directory` calls on the same
+/// path reuse the existing watcher instead of leaking a new thread.
+#[derive(Default)]
+pub struct WatcherRegistry {
+    watchers: Mutex<HashMap<PathBuf, RecommendedWatcher>>,
+}
+
+impl WatcherRegistry {
+    pub fn watch(&self, app: AppHandle, root: PathBuf) -> Result<(), String> {
+        let mut watchers = self.watchers.lock().map_err(|e| e.to_string())?;
+        if watchers.contains_key(&root) {
+            return Ok(());
+        }
+
+        let watch_root = root.clone();
+        let mut watcher = notify::recommended_watcher(move |event: notify::Result<Event>| {
+            let Ok(event) = event else { return };
+            handle_event(&app, &watch_root, event);
+        })
+        .map_err(|e| e.to_string())?;
+
+        watcher
+            .watch(&root, RecursiveMode::Recursive)
+            .map_err(|e| e.to_string())?;
+        watchers.insert(root, watcher);
+        Ok(())
+    }
+
+    pub fn unwatch(&self, root: &Path) -> Result<(), String> {
+        let mut watchers = self.watchers.lock().map_err(|e| e.to_string())?;
+        watchers.remove(root);
+        Ok(())
+    }
+}
+
+fn handle_event(app: &AppHandle, watch_root: &Path, event: Event) {
+    let registry = RuleRegistry::with_default_rules();
+    for (path, kind) in classify_event_paths(&event) {
+        let issues = match kind {
+            ChangeKind::Removed => Vec::new(),
+            _ => analyze_single_file(&registry, &path),
+        };
+        let change = FileChange {
+            path: path.display().to_string(),
+            kind,
+            issues,
+        };
+        if let Err(e) = app.emit(FILE_CHANGE_EVENT, &change) {
+            log::error!("failed to emit {FILE_CHANGE_EVENT} for {}: {e}", watch_root.display());
+        }
+    }
+}
+
+/// Maps a `notify` event to the `(path, ChangeKind)` pairs it represents.
+///
+/// Renames need special handling: a `RenameMode::Both` event carries both
+/// the old and new path, and the old path must be reported as `Removed`
+/// (not `Modified` — the file is gone, and `load_source_file` failing
+/// silently would otherwise make the frontend think it became issue-free
+/// rather than disappearing). `RenameMode::From`/`To` events carry only
+/// one path each and are reported the same way.
+fn classify_event_paths(event: &Event) -> Vec<(PathBuf, ChangeKind)> {
+    match event.kind {
+        EventKind::Create(_) => with_kind(&event.paths, ChangeKind::Created),
+        EventKind::Remove(_) => with_kind(&event.paths, ChangeKind::Removed),
+        EventKind::Modify(ModifyKind::Name(RenameMode::Both)) => match event.paths.as_slice() {
+            [from, to] => vec![(from.clone(), ChangeKind::Removed), (to.clone(), ChangeKind::Created)],
+            paths => with_kind(paths, ChangeKind::Modified),
+        },
+        EventKind::Modify(ModifyKind::Name(RenameMode::From)) => with_kind(&event.paths, ChangeKind::Removed),
+        EventKind::Modify(ModifyKind::Name(RenameMode::To)) => with_kind(&event.paths, ChangeKind::Created),
+        EventKind::Modify(_) => with_kind(&event.paths, ChangeKind::Modified),
+        _ => Vec::new(),
+    }
+}
+
+fn with_kind(paths: &[PathBuf], kind: ChangeKind) -> Vec<(PathBuf, ChangeKind)> {
+    paths.iter().cloned().map(|p| (p, kind)).collect()
+}
+
+fn analyze_single_file(registry: &RuleRegistry, path: &Path) -> Vec<Issue> {
+    let Some(file) = load_source_file(path) else {
+        return Vec::new();
+    };
+    registry.analyze_file(&file)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use notify::event::CreateKind;
+
+    #[test]
+    fn rename_both_reports_old_path_as_removed_and_new_path_as_created() {
+        let event = Event::new(EventKind::Modify(ModifyKind::Name(RenameMode::Both)))
+            .add_path(PathBuf::from("src/old.ts"))
+            .add_path(PathBuf::from("src/new.ts"));
+
+        let mapped = classify_event_paths(&event);
+
+        assert_eq!(
+            mapped,
+            vec![
+                (PathBuf::from("src/old.ts"), ChangeKind::Removed),
+                (PathBuf::from("src/new.ts"), ChangeKind::Created),
+            ]
+        );
+    }
+
+    #[test]
+    fn rename_from_reports_removed() {
+        let event = Event::new(EventKind::Modify(ModifyKind::Name(RenameMode::From)))
+            .add_path(PathBuf::from("src/old.ts"));
+
+        assert_eq!(
+            classify_event_paths(&event),
+            vec![(PathBuf::from("src/old.ts"), ChangeKind::Removed)]
+        );
+    }
+
+    #[test]
+    fn rename_to_reports_created() {
+        let event = Event::new(EventKind::Modify(ModifyKind::Name(RenameMode::To)))
+            .add_path(PathBuf::from("src/new.ts"));
+
+        assert_eq!(
+            classify_event_paths(&event),
+            vec![(PathBuf::from("src/new.ts"), ChangeKind::Created)]
+        );
+    }
+
+    #[test]
+    fn plain_create_and_modify_still_map_through() {
+        let created = Event::new(EventKind::Create(CreateKind::File)).add_path(PathBuf::from("src/a.ts"));
+        assert_eq!(
+            classify_event_paths(&created),
+            vec![(PathBuf::from("src/a.ts"), ChangeKind::Created)]
+        );
+
+        let modified = Event::new(EventKind::Modify(ModifyKind::Data(notify::event::DataChange::Content)))
+            .add_path(PathBuf::from("src/a.ts"));
+        assert_eq!(
+            classify_event_paths(&modified),
+            vec![(PathBuf::from("src/a.ts"), ChangeKind::Modified)]
+        );
+    }
+}